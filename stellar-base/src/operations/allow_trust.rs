@@ -0,0 +1,246 @@
+use crate::crypto::{MuxedAccount, PublicKey};
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// The asset code a trustline is authorized for, as either a 4- or
+/// 12-byte alphanumeric code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetCode {
+    CreditAlphanum4(String),
+    CreditAlphanum12(String),
+}
+
+impl AssetCode {
+    fn to_xdr(&self) -> Result<xdr::AllowTrustOpAsset> {
+        match self {
+            AssetCode::CreditAlphanum4(code) => {
+                let mut buf = [0u8; 4];
+                let bytes = code.as_bytes();
+                if bytes.len() > 4 {
+                    return Err(Error::InvalidOperation("asset code too long".to_string()));
+                }
+                buf[..bytes.len()].copy_from_slice(bytes);
+                Ok(xdr::AllowTrustOpAsset::AssetTypeCreditAlphanum4(buf))
+            }
+            AssetCode::CreditAlphanum12(code) => {
+                let mut buf = [0u8; 12];
+                let bytes = code.as_bytes();
+                if bytes.len() > 12 {
+                    return Err(Error::InvalidOperation("asset code too long".to_string()));
+                }
+                buf[..bytes.len()].copy_from_slice(bytes);
+                Ok(xdr::AllowTrustOpAsset::AssetTypeCreditAlphanum12(buf))
+            }
+        }
+    }
+
+    fn from_xdr(x: &xdr::AllowTrustOpAsset) -> Result<AssetCode> {
+        match x {
+            xdr::AllowTrustOpAsset::AssetTypeCreditAlphanum4(buf) => {
+                let code = code_from_padded_bytes(buf)?;
+                Ok(AssetCode::CreditAlphanum4(code))
+            }
+            xdr::AllowTrustOpAsset::AssetTypeCreditAlphanum12(buf) => {
+                let code = code_from_padded_bytes(buf)?;
+                Ok(AssetCode::CreditAlphanum12(code))
+            }
+        }
+    }
+}
+
+fn code_from_padded_bytes(buf: &[u8]) -> Result<String> {
+    let end = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    String::from_utf8(buf[..end].to_vec())
+        .map_err(|_| Error::InvalidOperation("invalid asset code".to_string()))
+}
+
+/// How much a trustline is authorized to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustLineFlag {
+    /// The trustline is not authorized.
+    Unauthorized,
+    /// The trustline is fully authorized.
+    Authorized,
+    /// The trustline is authorized to maintain liabilities, but not to
+    /// otherwise send or receive the asset.
+    AuthorizedToMaintainLiabilities,
+}
+
+impl TrustLineFlag {
+    fn to_xdr(self) -> u32 {
+        match self {
+            TrustLineFlag::Unauthorized => 0,
+            TrustLineFlag::Authorized => 1,
+            TrustLineFlag::AuthorizedToMaintainLiabilities => 2,
+        }
+    }
+
+    fn from_xdr(x: u32) -> Result<TrustLineFlag> {
+        match x {
+            0 => Ok(TrustLineFlag::Unauthorized),
+            1 => Ok(TrustLineFlag::Authorized),
+            2 => Ok(TrustLineFlag::AuthorizedToMaintainLiabilities),
+            _ => Err(Error::InvalidOperation(
+                "invalid trustline authorization flag".to_string(),
+            )),
+        }
+    }
+}
+
+/// Authorizes (or revokes authorization for) another account's trustline
+/// to one of the source account's assets.
+///
+/// Only the issuer of an asset can call this operation, and only when the
+/// asset was created with the `AUTH_REQUIRED` flag set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowTrustOperation {
+    source_account: Option<MuxedAccount>,
+    trustor: PublicKey,
+    asset_code: AssetCode,
+    authorize: TrustLineFlag,
+}
+
+#[derive(Debug, Default)]
+pub struct AllowTrustOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    trustor: Option<PublicKey>,
+    asset_code: Option<AssetCode>,
+    authorize: Option<TrustLineFlag>,
+}
+
+impl AllowTrustOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the account whose trustline is being authorized.
+    pub fn trustor(&self) -> &PublicKey {
+        &self.trustor
+    }
+
+    /// Retrieves the asset code of the trustline being authorized.
+    pub fn asset_code(&self) -> &AssetCode {
+        &self.asset_code
+    }
+
+    /// Retrieves the authorization level being granted.
+    pub fn authorize(&self) -> TrustLineFlag {
+        self.authorize
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let trustor = self.trustor.to_xdr()?;
+        let asset = self.asset_code.to_xdr()?;
+        let inner = xdr::AllowTrustOp {
+            trustor,
+            asset,
+            authorize: self.authorize.to_xdr(),
+        };
+        Ok(xdr::OperationBody::AllowTrust(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::AllowTrustOp,
+    ) -> Result<AllowTrustOperation> {
+        let trustor = PublicKey::from_xdr(&x.trustor)?;
+        let asset_code = AssetCode::from_xdr(&x.asset)?;
+        let authorize = TrustLineFlag::from_xdr(x.authorize)?;
+        Ok(AllowTrustOperation {
+            source_account,
+            trustor,
+            asset_code,
+            authorize,
+        })
+    }
+}
+
+impl AllowTrustOperationBuilder {
+    pub fn new() -> AllowTrustOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> AllowTrustOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the account whose trustline is being authorized.
+    pub fn with_trustor(mut self, trustor: PublicKey) -> AllowTrustOperationBuilder {
+        self.trustor = Some(trustor);
+        self
+    }
+
+    /// Sets the asset code of the trustline being authorized.
+    pub fn with_asset_code(mut self, asset_code: AssetCode) -> AllowTrustOperationBuilder {
+        self.asset_code = Some(asset_code);
+        self
+    }
+
+    /// Sets the authorization level being granted.
+    pub fn with_authorize(mut self, authorize: TrustLineFlag) -> AllowTrustOperationBuilder {
+        self.authorize = Some(authorize);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let trustor = self
+            .trustor
+            .ok_or_else(|| Error::InvalidOperation("missing trustor".to_string()))?;
+        let asset_code = self
+            .asset_code
+            .ok_or_else(|| Error::InvalidOperation("missing asset code".to_string()))?;
+        let authorize = self
+            .authorize
+            .ok_or_else(|| Error::InvalidOperation("missing authorization level".to_string()))?;
+        Ok(Operation::AllowTrust(AllowTrustOperation {
+            source_account: self.source_account,
+            trustor,
+            asset_code,
+            authorize,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_allow_trust_round_trip() {
+        let op = crate::operations::allow_trust()
+            .with_source_account(keypair0().public_key().clone())
+            .with_trustor(keypair1().public_key().clone())
+            .with_asset_code(AssetCode::CreditAlphanum4("USD".to_string()))
+            .with_authorize(TrustLineFlag::Authorized)
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAcAAAAAJcrx2g/Hbs/ohF5CVFG7B5JJSJR+OqDKzDGK7dKHZH4AAAABVVNEAAAAAAE=",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
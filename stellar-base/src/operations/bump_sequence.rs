@@ -0,0 +1,108 @@
+use crate::crypto::MuxedAccount;
+use crate::error::Result;
+use crate::operations::Operation;
+use crate::xdr;
+
+/// Bumps the source account's sequence number to `bump_to`, allowing it to
+/// invalidate any pre-authorized transactions or signed transactions with
+/// a lower sequence number.
+///
+/// Has no effect if `bump_to` is lower than the account's current sequence
+/// number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BumpSequenceOperation {
+    source_account: Option<MuxedAccount>,
+    bump_to: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct BumpSequenceOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    bump_to: i64,
+}
+
+impl BumpSequenceOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the sequence number the source account is bumped to.
+    pub fn bump_to(&self) -> i64 {
+        self.bump_to
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let inner = xdr::BumpSequenceOp {
+            bump_to: self.bump_to,
+        };
+        Ok(xdr::OperationBody::BumpSequence(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::BumpSequenceOp,
+    ) -> Result<BumpSequenceOperation> {
+        Ok(BumpSequenceOperation {
+            source_account,
+            bump_to: x.bump_to,
+        })
+    }
+}
+
+impl BumpSequenceOperationBuilder {
+    pub fn new() -> BumpSequenceOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> BumpSequenceOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the sequence number to bump the source account to.
+    pub fn with_bump_to(mut self, bump_to: i64) -> BumpSequenceOperationBuilder {
+        self.bump_to = bump_to;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        Ok(Operation::BumpSequence(BumpSequenceOperation {
+            source_account: self.source_account,
+            bump_to: self.bump_to,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_bump_sequence_round_trip() {
+        let op = crate::operations::bump_sequence()
+            .with_source_account(keypair0().public_key().clone())
+            .with_bump_to(12345)
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAsAAAAAAAAwOQ==",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
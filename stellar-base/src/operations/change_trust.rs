@@ -0,0 +1,134 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// Creates, updates, or deletes a trustline, allowing the source account to
+/// hold a non-native asset.
+///
+/// Setting `limit` to `0` deletes the trustline; the account must first
+/// have a zero balance of `line` for the deletion to succeed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeTrustOperation {
+    source_account: Option<MuxedAccount>,
+    line: Asset,
+    limit: Stroops,
+}
+
+#[derive(Debug, Default)]
+pub struct ChangeTrustOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    line: Option<Asset>,
+    limit: Option<Stroops>,
+}
+
+impl ChangeTrustOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the asset this trustline is for.
+    pub fn line(&self) -> &Asset {
+        &self.line
+    }
+
+    /// Retrieves the trust limit. `0` means the trustline is being deleted.
+    pub fn limit(&self) -> &Stroops {
+        &self.limit
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let line = self.line.to_xdr()?;
+        let limit = self.limit.to_xdr_i64()?;
+        let inner = xdr::ChangeTrustOp { line, limit };
+        Ok(xdr::OperationBody::ChangeTrust(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ChangeTrustOp,
+    ) -> Result<ChangeTrustOperation> {
+        let line = Asset::from_xdr(&x.line)?;
+        let limit = Stroops::from_xdr_i64(x.limit)?;
+        Ok(ChangeTrustOperation {
+            source_account,
+            line,
+            limit,
+        })
+    }
+}
+
+impl ChangeTrustOperationBuilder {
+    pub fn new() -> ChangeTrustOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> ChangeTrustOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the asset this trustline is for.
+    pub fn with_line(mut self, line: Asset) -> ChangeTrustOperationBuilder {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the trust limit. Pass `Stroops::new(0)` to delete the
+    /// trustline.
+    pub fn with_limit(mut self, limit: Stroops) -> ChangeTrustOperationBuilder {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let line = self
+            .line
+            .ok_or_else(|| Error::InvalidOperation("missing trustline asset".to_string()))?;
+        let limit = self
+            .limit
+            .ok_or_else(|| Error::InvalidOperation("missing trust limit".to_string()))?;
+        Ok(Operation::ChangeTrust(ChangeTrustOperation {
+            source_account: self.source_account,
+            line,
+            limit,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_change_trust_round_trip() {
+        let op = crate::operations::change_trust()
+            .with_source_account(keypair0().public_key().clone())
+            .with_line(Asset::new_native())
+            .with_limit(Stroops::new(1_000_000_000))
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAYAAAAAAAAAADuaygA=",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::price::Price;
+use crate::xdr;
+
+/// Creates an offer to sell one asset for another without taking on the
+/// liabilities of a regular offer.
+///
+/// A passive offer never crosses an existing offer at the same price; it
+/// waits for the other side to improve on its price instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePassiveSellOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    amount: Stroops,
+    price: Price,
+}
+
+#[derive(Debug, Default)]
+pub struct CreatePassiveSellOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    amount: Option<Stroops>,
+    price: Option<Price>,
+}
+
+impl CreatePassiveSellOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves the amount of `selling` being offered.
+    pub fn amount(&self) -> &Stroops {
+        &self.amount
+    }
+
+    /// Retrieves the price of `selling` in terms of `buying` for this
+    /// passive offer.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr()?;
+        let buying = self.buying.to_xdr()?;
+        let amount = self.amount.to_xdr_i64()?;
+        let price = self.price.to_xdr()?;
+        let inner = xdr::CreatePassiveSellOfferOp {
+            selling,
+            buying,
+            amount,
+            price,
+        };
+        Ok(xdr::OperationBody::CreatePassiveSellOffer(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::CreatePassiveSellOfferOp,
+    ) -> Result<CreatePassiveSellOfferOperation> {
+        let selling = Asset::from_xdr(&x.selling)?;
+        let buying = Asset::from_xdr(&x.buying)?;
+        let amount = Stroops::from_xdr_i64(x.amount)?;
+        let price = Price::from_xdr(&x.price)?;
+        Ok(CreatePassiveSellOfferOperation {
+            source_account,
+            selling,
+            buying,
+            amount,
+            price,
+        })
+    }
+}
+
+impl CreatePassiveSellOfferOperationBuilder {
+    pub fn new() -> CreatePassiveSellOfferOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> CreatePassiveSellOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the asset being sold.
+    pub fn with_selling(mut self, selling: Asset) -> CreatePassiveSellOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    /// Sets the asset being bought.
+    pub fn with_buying(mut self, buying: Asset) -> CreatePassiveSellOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    /// Sets the amount of `selling` being offered.
+    pub fn with_amount(mut self, amount: Stroops) -> CreatePassiveSellOfferOperationBuilder {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the price of `selling` in terms of `buying`.
+    pub fn with_price(mut self, price: Price) -> CreatePassiveSellOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self
+            .selling
+            .ok_or_else(|| Error::InvalidOperation("missing selling asset".to_string()))?;
+        let buying = self
+            .buying
+            .ok_or_else(|| Error::InvalidOperation("missing buying asset".to_string()))?;
+        let amount = self
+            .amount
+            .ok_or_else(|| Error::InvalidOperation("missing amount".to_string()))?;
+        let price = self
+            .price
+            .ok_or_else(|| Error::InvalidOperation("missing price".to_string()))?;
+        Ok(Operation::CreatePassiveSellOffer(
+            CreatePassiveSellOfferOperation {
+                source_account: self.source_account,
+                selling,
+                buying,
+                amount,
+                price,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_create_passive_sell_offer_round_trip() {
+        let op = crate::operations::create_passive_sell_offer()
+            .with_source_account(keypair0().public_key().clone())
+            .with_selling(Asset::new_native())
+            .with_buying(Asset::new_native())
+            .with_amount(Stroops::new(1000))
+            .with_price(Price::new(1, 3))
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAQAAAAAAAAAAAAAAAAAAAPoAAAAAQAAAAM=",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::price::Price;
+use crate::xdr;
+
+/// Creates, updates, or deletes an offer to buy one asset for another.
+///
+/// Setting `offer_id` to `0` creates a new offer. Any other value updates
+/// (or, if `buy_amount` is zero, deletes) the existing offer with that id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManageBuyOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    buy_amount: Stroops,
+    price: Price,
+    offer_id: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ManageBuyOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    buy_amount: Option<Stroops>,
+    price: Option<Price>,
+    offer_id: i64,
+}
+
+impl ManageBuyOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves the amount of `buying` being sought.
+    pub fn buy_amount(&self) -> &Stroops {
+        &self.buy_amount
+    }
+
+    /// Retrieves the price at which `selling` is exchanged for `buying`,
+    /// expressed as units of `buying` per unit of `selling`.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    /// Retrieves the id of the offer being managed, or `0` if this creates
+    /// a new offer.
+    pub fn offer_id(&self) -> i64 {
+        self.offer_id
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr()?;
+        let buying = self.buying.to_xdr()?;
+        let buy_amount = self.buy_amount.to_xdr_i64()?;
+        let price = self.price.to_xdr()?;
+        let inner = xdr::ManageBuyOfferOp {
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id: self.offer_id,
+        };
+        Ok(xdr::OperationBody::ManageBuyOffer(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ManageBuyOfferOp,
+    ) -> Result<ManageBuyOfferOperation> {
+        let selling = Asset::from_xdr(&x.selling)?;
+        let buying = Asset::from_xdr(&x.buying)?;
+        let buy_amount = Stroops::from_xdr_i64(x.buy_amount)?;
+        let price = Price::from_xdr(&x.price)?;
+        Ok(ManageBuyOfferOperation {
+            source_account,
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id: x.offer_id,
+        })
+    }
+}
+
+impl ManageBuyOfferOperationBuilder {
+    pub fn new() -> ManageBuyOfferOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> ManageBuyOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the asset being sold.
+    pub fn with_selling(mut self, selling: Asset) -> ManageBuyOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    /// Sets the asset being bought.
+    pub fn with_buying(mut self, buying: Asset) -> ManageBuyOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    /// Sets the amount of `buying` being sought.
+    pub fn with_buy_amount(mut self, buy_amount: Stroops) -> ManageBuyOfferOperationBuilder {
+        self.buy_amount = Some(buy_amount);
+        self
+    }
+
+    /// Sets the price at which `selling` is exchanged for `buying`.
+    pub fn with_price(mut self, price: Price) -> ManageBuyOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    /// Sets the id of the offer being managed. Defaults to `0`, which
+    /// creates a new offer.
+    pub fn with_offer_id(mut self, offer_id: i64) -> ManageBuyOfferOperationBuilder {
+        self.offer_id = offer_id;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self
+            .selling
+            .ok_or_else(|| Error::InvalidOperation("missing selling asset".to_string()))?;
+        let buying = self
+            .buying
+            .ok_or_else(|| Error::InvalidOperation("missing buying asset".to_string()))?;
+        let buy_amount = self
+            .buy_amount
+            .ok_or_else(|| Error::InvalidOperation("missing buy amount".to_string()))?;
+        let price = self
+            .price
+            .ok_or_else(|| Error::InvalidOperation("missing price".to_string()))?;
+        Ok(Operation::ManageBuyOffer(ManageBuyOfferOperation {
+            source_account: self.source_account,
+            selling,
+            buying,
+            buy_amount,
+            price,
+            offer_id: self.offer_id,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_manage_buy_offer_round_trip() {
+        let op = crate::operations::manage_buy_offer()
+            .with_source_account(keypair0().public_key().clone())
+            .with_selling(Asset::new_native())
+            .with_buying(Asset::new_native())
+            .with_buy_amount(Stroops::new(1000))
+            .with_price(Price::new(2, 1))
+            .with_offer_id(0)
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAwAAAAAAAAAAAAAAAAAAAPoAAAAAgAAAAEAAAAAAAAAAA==",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
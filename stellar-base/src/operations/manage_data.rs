@@ -0,0 +1,163 @@
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+const MANAGE_DATA_NAME_MAX_LENGTH: usize = 64;
+const MANAGE_DATA_VALUE_MAX_LENGTH: usize = 64;
+
+/// Sets, modifies, or deletes an entry in the source account's data store.
+///
+/// Passing `None` as the value deletes the entry named `name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManageDataOperation {
+    source_account: Option<MuxedAccount>,
+    name: String,
+    value: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Default)]
+pub struct ManageDataOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    name: Option<String>,
+    value: Option<Vec<u8>>,
+}
+
+impl ManageDataOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the data entry's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the data entry's value. `None` means the entry is being
+    /// deleted.
+    pub fn value(&self) -> &Option<Vec<u8>> {
+        &self.value
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let data_name = self.name.clone().into_bytes();
+        let data_value = self.value.clone();
+        let inner = xdr::ManageDataOp {
+            data_name,
+            data_value,
+        };
+        Ok(xdr::OperationBody::ManageData(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ManageDataOp,
+    ) -> Result<ManageDataOperation> {
+        let name = String::from_utf8(x.data_name.clone())
+            .map_err(|_| Error::InvalidOperation("invalid data name".to_string()))?;
+        let value = x.data_value.clone();
+        Ok(ManageDataOperation {
+            source_account,
+            name,
+            value,
+        })
+    }
+}
+
+impl ManageDataOperationBuilder {
+    pub fn new() -> ManageDataOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> ManageDataOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the data entry's name.
+    pub fn with_name(mut self, name: String) -> ManageDataOperationBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the data entry's value. Pass `None` to delete the entry.
+    pub fn with_value(mut self, value: Option<Vec<u8>>) -> ManageDataOperationBuilder {
+        self.value = value;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let name = self
+            .name
+            .ok_or_else(|| Error::InvalidOperation("missing data name".to_string()))?;
+        if name.len() > MANAGE_DATA_NAME_MAX_LENGTH {
+            return Err(Error::InvalidOperation("data name too long".to_string()));
+        }
+        if let Some(value) = &self.value {
+            if value.len() > MANAGE_DATA_VALUE_MAX_LENGTH {
+                return Err(Error::InvalidOperation("data value too long".to_string()));
+            }
+        }
+        Ok(Operation::ManageData(ManageDataOperation {
+            source_account: self.source_account,
+            name,
+            value: self.value,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_manage_data_round_trip() {
+        let op = crate::operations::manage_data()
+            .with_source_account(keypair0().public_key().clone())
+            .with_name("key".to_string())
+            .with_value(Some(b"value".to_vec()))
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAoAAAADa2V5AAAAAAEAAAAFdmFsdWUAAAA=",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+
+    #[test]
+    fn test_manage_data_delete_entry() {
+        let op = crate::operations::manage_data()
+            .with_name("key".to_string())
+            .with_value(None)
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!("AAAAAAAAAAoAAAADa2V5AAAAAAA=", encoded);
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+
+    #[test]
+    fn test_manage_data_rejects_long_name() {
+        let result = crate::operations::manage_data()
+            .with_name("x".repeat(MANAGE_DATA_NAME_MAX_LENGTH + 1))
+            .build();
+        assert!(result.is_err());
+    }
+}
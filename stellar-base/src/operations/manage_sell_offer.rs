@@ -0,0 +1,200 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::price::Price;
+use crate::xdr;
+
+/// Creates, updates, or deletes an offer to sell one asset for another.
+///
+/// Setting `offer_id` to `0` creates a new offer. Any other value updates
+/// (or, if `amount` is zero, deletes) the existing offer with that id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManageSellOfferOperation {
+    source_account: Option<MuxedAccount>,
+    selling: Asset,
+    buying: Asset,
+    amount: Stroops,
+    price: Price,
+    offer_id: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ManageSellOfferOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    selling: Option<Asset>,
+    buying: Option<Asset>,
+    amount: Option<Stroops>,
+    price: Option<Price>,
+    offer_id: i64,
+}
+
+impl ManageSellOfferOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the asset being sold.
+    pub fn selling(&self) -> &Asset {
+        &self.selling
+    }
+
+    /// Retrieves the asset being bought.
+    pub fn buying(&self) -> &Asset {
+        &self.buying
+    }
+
+    /// Retrieves the amount of `selling` being offered.
+    pub fn amount(&self) -> &Stroops {
+        &self.amount
+    }
+
+    /// Retrieves the price of `selling` in terms of `buying`, i.e. how much
+    /// `buying` is paid per unit of `selling`.
+    pub fn price(&self) -> &Price {
+        &self.price
+    }
+
+    /// Retrieves the id of the offer being managed, or `0` if this creates
+    /// a new offer.
+    pub fn offer_id(&self) -> i64 {
+        self.offer_id
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let selling = self.selling.to_xdr()?;
+        let buying = self.buying.to_xdr()?;
+        let amount = self.amount.to_xdr_i64()?;
+        let price = self.price.to_xdr()?;
+        let inner = xdr::ManageSellOfferOp {
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id: self.offer_id,
+        };
+        Ok(xdr::OperationBody::ManageSellOffer(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::ManageSellOfferOp,
+    ) -> Result<ManageSellOfferOperation> {
+        let selling = Asset::from_xdr(&x.selling)?;
+        let buying = Asset::from_xdr(&x.buying)?;
+        let amount = Stroops::from_xdr_i64(x.amount)?;
+        let price = Price::from_xdr(&x.price)?;
+        Ok(ManageSellOfferOperation {
+            source_account,
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id: x.offer_id,
+        })
+    }
+}
+
+impl ManageSellOfferOperationBuilder {
+    pub fn new() -> ManageSellOfferOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> ManageSellOfferOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the asset being sold.
+    pub fn with_selling(mut self, selling: Asset) -> ManageSellOfferOperationBuilder {
+        self.selling = Some(selling);
+        self
+    }
+
+    /// Sets the asset being bought.
+    pub fn with_buying(mut self, buying: Asset) -> ManageSellOfferOperationBuilder {
+        self.buying = Some(buying);
+        self
+    }
+
+    /// Sets the amount of `selling` being offered.
+    pub fn with_amount(mut self, amount: Stroops) -> ManageSellOfferOperationBuilder {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the price of `selling` in terms of `buying`.
+    pub fn with_price(mut self, price: Price) -> ManageSellOfferOperationBuilder {
+        self.price = Some(price);
+        self
+    }
+
+    /// Sets the id of the offer being managed. Defaults to `0`, which
+    /// creates a new offer.
+    pub fn with_offer_id(mut self, offer_id: i64) -> ManageSellOfferOperationBuilder {
+        self.offer_id = offer_id;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let selling = self
+            .selling
+            .ok_or_else(|| Error::InvalidOperation("missing selling asset".to_string()))?;
+        let buying = self
+            .buying
+            .ok_or_else(|| Error::InvalidOperation("missing buying asset".to_string()))?;
+        let amount = self
+            .amount
+            .ok_or_else(|| Error::InvalidOperation("missing amount".to_string()))?;
+        let price = self
+            .price
+            .ok_or_else(|| Error::InvalidOperation("missing price".to_string()))?;
+        Ok(Operation::ManageSellOffer(ManageSellOfferOperation {
+            source_account: self.source_account,
+            selling,
+            buying,
+            amount,
+            price,
+            offer_id: self.offer_id,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_manage_sell_offer_round_trip() {
+        let op = crate::operations::manage_sell_offer()
+            .with_source_account(keypair0().public_key().clone())
+            .with_selling(Asset::new_native())
+            .with_buying(Asset::new_native())
+            .with_amount(Stroops::new(1000))
+            .with_price(Price::new(1, 2))
+            .with_offer_id(0)
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAMAAAAAAAAAAAAAAAAAAAPoAAAAAQAAAAIAAAAAAAAAAA==",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
@@ -6,26 +6,57 @@ use xdr_rs_serialize::de::XDRIn;
 use xdr_rs_serialize::ser::XDROut;
 
 mod account_merge;
+mod allow_trust;
+mod bump_sequence;
+mod change_trust;
 mod create_account;
+mod create_passive_sell_offer;
 mod inflation;
+mod manage_buy_offer;
+mod manage_data;
+mod manage_sell_offer;
 mod path_payment_strict_receive;
+mod path_payment_strict_send;
 mod payment;
+mod set_options;
 
 pub use account_merge::{AccountMergeOperation, AccountMergeOperationBuilder};
+pub use allow_trust::{AllowTrustOperation, AllowTrustOperationBuilder, AssetCode, TrustLineFlag};
+pub use bump_sequence::{BumpSequenceOperation, BumpSequenceOperationBuilder};
+pub use change_trust::{ChangeTrustOperation, ChangeTrustOperationBuilder};
 pub use create_account::{CreateAccountOperation, CreateAccountOperationBuilder};
+pub use create_passive_sell_offer::{
+    CreatePassiveSellOfferOperation, CreatePassiveSellOfferOperationBuilder,
+};
 pub use inflation::{InflationOperation, InflationOperationBuilder};
+pub use manage_buy_offer::{ManageBuyOfferOperation, ManageBuyOfferOperationBuilder};
+pub use manage_data::{ManageDataOperation, ManageDataOperationBuilder};
+pub use manage_sell_offer::{ManageSellOfferOperation, ManageSellOfferOperationBuilder};
 pub use path_payment_strict_receive::{
     PathPaymentStrictReceiveOperation, PathPaymentStrictReceiveOperationBuilder,
 };
+pub use path_payment_strict_send::{
+    PathPaymentStrictSendOperation, PathPaymentStrictSendOperationBuilder,
+};
 pub use payment::{PaymentOperation, PaymentOperationBuilder};
+pub use set_options::{SetOptionsOperation, SetOptionsOperationBuilder, Signer, SignerKey};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Operation {
     CreateAccount(CreateAccountOperation),
     Payment(PaymentOperation),
     PathPaymentStrictReceive(PathPaymentStrictReceiveOperation),
+    PathPaymentStrictSend(PathPaymentStrictSendOperation),
+    ManageSellOffer(ManageSellOfferOperation),
+    CreatePassiveSellOffer(CreatePassiveSellOfferOperation),
+    ManageBuyOffer(ManageBuyOfferOperation),
+    SetOptions(SetOptionsOperation),
+    ChangeTrust(ChangeTrustOperation),
+    AllowTrust(AllowTrustOperation),
     AccountMerge(AccountMergeOperation),
     Inflation(InflationOperation),
+    ManageData(ManageDataOperation),
+    BumpSequence(BumpSequenceOperation),
 }
 
 pub fn create_account() -> CreateAccountOperationBuilder {
@@ -40,6 +71,34 @@ pub fn path_payment_strict_receive() -> PathPaymentStrictReceiveOperationBuilder
     PathPaymentStrictReceiveOperationBuilder::new()
 }
 
+pub fn path_payment_strict_send() -> PathPaymentStrictSendOperationBuilder {
+    PathPaymentStrictSendOperationBuilder::new()
+}
+
+pub fn manage_sell_offer() -> ManageSellOfferOperationBuilder {
+    ManageSellOfferOperationBuilder::new()
+}
+
+pub fn create_passive_sell_offer() -> CreatePassiveSellOfferOperationBuilder {
+    CreatePassiveSellOfferOperationBuilder::new()
+}
+
+pub fn manage_buy_offer() -> ManageBuyOfferOperationBuilder {
+    ManageBuyOfferOperationBuilder::new()
+}
+
+pub fn set_options() -> SetOptionsOperationBuilder {
+    SetOptionsOperationBuilder::new()
+}
+
+pub fn change_trust() -> ChangeTrustOperationBuilder {
+    ChangeTrustOperationBuilder::new()
+}
+
+pub fn allow_trust() -> AllowTrustOperationBuilder {
+    AllowTrustOperationBuilder::new()
+}
+
 pub fn account_merge() -> AccountMergeOperationBuilder {
     AccountMergeOperationBuilder::new()
 }
@@ -48,6 +107,14 @@ pub fn inflation() -> InflationOperationBuilder {
     InflationOperationBuilder::new()
 }
 
+pub fn manage_data() -> ManageDataOperationBuilder {
+    ManageDataOperationBuilder::new()
+}
+
+pub fn bump_sequence() -> BumpSequenceOperationBuilder {
+    BumpSequenceOperationBuilder::new()
+}
+
 impl Operation {
     pub fn create_account(&self) -> Option<&CreateAccountOperation> {
         match self {
@@ -82,6 +149,83 @@ impl Operation {
         self.path_payment_strict_receive().is_some()
     }
 
+    pub fn path_payment_strict_send(&self) -> Option<&PathPaymentStrictSendOperation> {
+        match self {
+            Operation::PathPaymentStrictSend(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_path_payment_strict_send(&self) -> bool {
+        self.path_payment_strict_send().is_some()
+    }
+
+    pub fn manage_sell_offer(&self) -> Option<&ManageSellOfferOperation> {
+        match self {
+            Operation::ManageSellOffer(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_manage_sell_offer(&self) -> bool {
+        self.manage_sell_offer().is_some()
+    }
+
+    pub fn create_passive_sell_offer(&self) -> Option<&CreatePassiveSellOfferOperation> {
+        match self {
+            Operation::CreatePassiveSellOffer(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_create_passive_sell_offer(&self) -> bool {
+        self.create_passive_sell_offer().is_some()
+    }
+
+    pub fn manage_buy_offer(&self) -> Option<&ManageBuyOfferOperation> {
+        match self {
+            Operation::ManageBuyOffer(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_manage_buy_offer(&self) -> bool {
+        self.manage_buy_offer().is_some()
+    }
+
+    pub fn set_options(&self) -> Option<&SetOptionsOperation> {
+        match self {
+            Operation::SetOptions(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_set_options(&self) -> bool {
+        self.set_options().is_some()
+    }
+
+    pub fn change_trust(&self) -> Option<&ChangeTrustOperation> {
+        match self {
+            Operation::ChangeTrust(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_change_trust(&self) -> bool {
+        self.change_trust().is_some()
+    }
+
+    pub fn allow_trust(&self) -> Option<&AllowTrustOperation> {
+        match self {
+            Operation::AllowTrust(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_allow_trust(&self) -> bool {
+        self.allow_trust().is_some()
+    }
+
     pub fn account_merge(&self) -> Option<&AccountMergeOperation> {
         match self {
             Operation::AccountMerge(op) => Some(op),
@@ -104,13 +248,44 @@ impl Operation {
         self.inflation().is_some()
     }
 
+    pub fn manage_data(&self) -> Option<&ManageDataOperation> {
+        match self {
+            Operation::ManageData(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_manage_data(&self) -> bool {
+        self.manage_data().is_some()
+    }
+
+    pub fn bump_sequence(&self) -> Option<&BumpSequenceOperation> {
+        match self {
+            Operation::BumpSequence(op) => Some(op),
+            _ => None,
+        }
+    }
+
+    pub fn is_bump_sequence(&self) -> bool {
+        self.bump_sequence().is_some()
+    }
+
     pub fn source_account(&self) -> &Option<MuxedAccount> {
         match self {
             Operation::CreateAccount(op) => op.source_account(),
             Operation::Payment(op) => op.source_account(),
             Operation::PathPaymentStrictReceive(op) => op.source_account(),
+            Operation::PathPaymentStrictSend(op) => op.source_account(),
+            Operation::ManageSellOffer(op) => op.source_account(),
+            Operation::CreatePassiveSellOffer(op) => op.source_account(),
+            Operation::ManageBuyOffer(op) => op.source_account(),
+            Operation::SetOptions(op) => op.source_account(),
+            Operation::ChangeTrust(op) => op.source_account(),
+            Operation::AllowTrust(op) => op.source_account(),
             Operation::AccountMerge(op) => op.source_account(),
             Operation::Inflation(op) => op.source_account(),
+            Operation::ManageData(op) => op.source_account(),
+            Operation::BumpSequence(op) => op.source_account(),
         }
     }
 
@@ -123,8 +298,17 @@ impl Operation {
             Operation::CreateAccount(op) => op.to_xdr_operation_body()?,
             Operation::Payment(op) => op.to_xdr_operation_body()?,
             Operation::PathPaymentStrictReceive(op) => op.to_xdr_operation_body()?,
+            Operation::PathPaymentStrictSend(op) => op.to_xdr_operation_body()?,
+            Operation::ManageSellOffer(op) => op.to_xdr_operation_body()?,
+            Operation::CreatePassiveSellOffer(op) => op.to_xdr_operation_body()?,
+            Operation::ManageBuyOffer(op) => op.to_xdr_operation_body()?,
+            Operation::SetOptions(op) => op.to_xdr_operation_body()?,
+            Operation::ChangeTrust(op) => op.to_xdr_operation_body()?,
+            Operation::AllowTrust(op) => op.to_xdr_operation_body()?,
             Operation::AccountMerge(op) => op.to_xdr_operation_body()?,
             Operation::Inflation(op) => op.to_xdr_operation_body()?,
+            Operation::ManageData(op) => op.to_xdr_operation_body()?,
+            Operation::BumpSequence(op) => op.to_xdr_operation_body()?,
         };
         Ok(xdr::Operation {
             source_account,
@@ -151,11 +335,27 @@ impl Operation {
                     PathPaymentStrictReceiveOperation::from_xdr_operation_body(source_account, op)?;
                 Ok(Operation::PathPaymentStrictReceive(inner))
             }
-            xdr::OperationBody::ManageSellOffer(op) => todo!(),
-            xdr::OperationBody::CreatePassiveSellOffer(op) => todo!(),
-            xdr::OperationBody::SetOptions(op) => todo!(),
-            xdr::OperationBody::ChangeTrust(op) => todo!(),
-            xdr::OperationBody::AllowTrust(op) => todo!(),
+            xdr::OperationBody::ManageSellOffer(op) => {
+                let inner = ManageSellOfferOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::ManageSellOffer(inner))
+            }
+            xdr::OperationBody::CreatePassiveSellOffer(op) => {
+                let inner =
+                    CreatePassiveSellOfferOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::CreatePassiveSellOffer(inner))
+            }
+            xdr::OperationBody::SetOptions(op) => {
+                let inner = SetOptionsOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::SetOptions(inner))
+            }
+            xdr::OperationBody::ChangeTrust(op) => {
+                let inner = ChangeTrustOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::ChangeTrust(inner))
+            }
+            xdr::OperationBody::AllowTrust(op) => {
+                let inner = AllowTrustOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::AllowTrust(inner))
+            }
             xdr::OperationBody::AccountMerge(op) => {
                 let inner = AccountMergeOperation::from_xdr_operation_body(source_account, op)?;
                 Ok(Operation::AccountMerge(inner))
@@ -164,10 +364,23 @@ impl Operation {
                 let inner = InflationOperation::from_xdr_operation_body(source_account)?;
                 Ok(Operation::Inflation(inner))
             }
-            xdr::OperationBody::ManageData(op) => todo!(),
-            xdr::OperationBody::BumpSequence(op) => todo!(),
-            xdr::OperationBody::ManageBuyOffer(op) => todo!(),
-            xdr::OperationBody::PathPaymentStrictSend(op) => todo!(),
+            xdr::OperationBody::ManageData(op) => {
+                let inner = ManageDataOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::ManageData(inner))
+            }
+            xdr::OperationBody::BumpSequence(op) => {
+                let inner = BumpSequenceOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::BumpSequence(inner))
+            }
+            xdr::OperationBody::ManageBuyOffer(op) => {
+                let inner = ManageBuyOfferOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::ManageBuyOffer(inner))
+            }
+            xdr::OperationBody::PathPaymentStrictSend(op) => {
+                let inner =
+                    PathPaymentStrictSendOperation::from_xdr_operation_body(source_account, op)?;
+                Ok(Operation::PathPaymentStrictSend(inner))
+            }
         }
     }
 }
@@ -0,0 +1,249 @@
+use crate::amount::Stroops;
+use crate::asset::Asset;
+use crate::crypto::MuxedAccount;
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// Sends an exact amount of one asset, crediting the destination with
+/// whatever a path of intermediate assets converts it into, as long as the
+/// destination receives at least `dest_min`.
+///
+/// This is the mirror image of [`PathPaymentStrictReceiveOperation`], which
+/// fixes the amount the destination receives instead of the amount sent.
+///
+/// [`PathPaymentStrictReceiveOperation`]: crate::operations::PathPaymentStrictReceiveOperation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPaymentStrictSendOperation {
+    source_account: Option<MuxedAccount>,
+    send_asset: Asset,
+    send_amount: Stroops,
+    destination: MuxedAccount,
+    dest_asset: Asset,
+    dest_min: Stroops,
+    path: Vec<Asset>,
+}
+
+#[derive(Debug, Default)]
+pub struct PathPaymentStrictSendOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    send_asset: Option<Asset>,
+    send_amount: Option<Stroops>,
+    destination: Option<MuxedAccount>,
+    dest_asset: Option<Asset>,
+    dest_min: Option<Stroops>,
+    path: Vec<Asset>,
+}
+
+impl PathPaymentStrictSendOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the asset being sent.
+    pub fn send_asset(&self) -> &Asset {
+        &self.send_asset
+    }
+
+    /// Retrieves the exact amount of `send_asset` being sent.
+    pub fn send_amount(&self) -> &Stroops {
+        &self.send_amount
+    }
+
+    /// Retrieves the payment destination.
+    pub fn destination(&self) -> &MuxedAccount {
+        &self.destination
+    }
+
+    /// Retrieves the asset the destination is credited with.
+    pub fn dest_asset(&self) -> &Asset {
+        &self.dest_asset
+    }
+
+    /// Retrieves the minimum amount of `dest_asset` the destination must
+    /// receive for the payment to succeed.
+    pub fn dest_min(&self) -> &Stroops {
+        &self.dest_min
+    }
+
+    /// Retrieves the ordered list of intermediate assets the payment is
+    /// converted through.
+    pub fn path(&self) -> &Vec<Asset> {
+        &self.path
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let send_asset = self.send_asset.to_xdr()?;
+        let send_amount = self.send_amount.to_xdr_i64()?;
+        let destination = self.destination.to_xdr()?;
+        let dest_asset = self.dest_asset.to_xdr()?;
+        let dest_min = self.dest_min.to_xdr_i64()?;
+        let path = self
+            .path
+            .iter()
+            .map(|asset| asset.to_xdr())
+            .collect::<Result<Vec<_>>>()?;
+        let inner = xdr::PathPaymentStrictSendOp {
+            send_asset,
+            send_amount,
+            destination,
+            dest_asset,
+            dest_min,
+            path,
+        };
+        Ok(xdr::OperationBody::PathPaymentStrictSend(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::PathPaymentStrictSendOp,
+    ) -> Result<PathPaymentStrictSendOperation> {
+        let send_asset = Asset::from_xdr(&x.send_asset)?;
+        let send_amount = Stroops::from_xdr_i64(x.send_amount)?;
+        let destination = MuxedAccount::from_xdr(&x.destination)?;
+        let dest_asset = Asset::from_xdr(&x.dest_asset)?;
+        let dest_min = Stroops::from_xdr_i64(x.dest_min)?;
+        let path = x
+            .path
+            .iter()
+            .map(Asset::from_xdr)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PathPaymentStrictSendOperation {
+            source_account,
+            send_asset,
+            send_amount,
+            destination,
+            dest_asset,
+            dest_min,
+            path,
+        })
+    }
+}
+
+impl PathPaymentStrictSendOperationBuilder {
+    pub fn new() -> PathPaymentStrictSendOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> PathPaymentStrictSendOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the asset being sent.
+    pub fn with_send_asset(mut self, send_asset: Asset) -> PathPaymentStrictSendOperationBuilder {
+        self.send_asset = Some(send_asset);
+        self
+    }
+
+    /// Sets the exact amount of `send_asset` being sent.
+    pub fn with_send_amount(
+        mut self,
+        send_amount: Stroops,
+    ) -> PathPaymentStrictSendOperationBuilder {
+        self.send_amount = Some(send_amount);
+        self
+    }
+
+    /// Sets the payment destination.
+    pub fn with_destination<D>(mut self, destination: D) -> PathPaymentStrictSendOperationBuilder
+    where
+        D: Into<MuxedAccount>,
+    {
+        self.destination = Some(destination.into());
+        self
+    }
+
+    /// Sets the asset the destination is credited with.
+    pub fn with_dest_asset(mut self, dest_asset: Asset) -> PathPaymentStrictSendOperationBuilder {
+        self.dest_asset = Some(dest_asset);
+        self
+    }
+
+    /// Sets the minimum amount of `dest_asset` the destination must receive.
+    pub fn with_dest_min(mut self, dest_min: Stroops) -> PathPaymentStrictSendOperationBuilder {
+        self.dest_min = Some(dest_min);
+        self
+    }
+
+    /// Sets the ordered list of intermediate assets the payment is
+    /// converted through.
+    pub fn with_path(mut self, path: Vec<Asset>) -> PathPaymentStrictSendOperationBuilder {
+        self.path = path;
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        let send_asset = self
+            .send_asset
+            .ok_or_else(|| Error::InvalidOperation("missing send asset".to_string()))?;
+        let send_amount = self
+            .send_amount
+            .ok_or_else(|| Error::InvalidOperation("missing send amount".to_string()))?;
+        let destination = self
+            .destination
+            .ok_or_else(|| Error::InvalidOperation("missing destination".to_string()))?;
+        let dest_asset = self
+            .dest_asset
+            .ok_or_else(|| Error::InvalidOperation("missing destination asset".to_string()))?;
+        let dest_min = self
+            .dest_min
+            .ok_or_else(|| Error::InvalidOperation("missing destination minimum".to_string()))?;
+        Ok(Operation::PathPaymentStrictSend(
+            PathPaymentStrictSendOperation {
+                source_account: self.source_account,
+                send_asset,
+                send_amount,
+                destination,
+                dest_asset,
+                dest_min,
+                path: self.path,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_path_payment_strict_send_round_trip() {
+        let op = crate::operations::path_payment_strict_send()
+            .with_source_account(keypair0().public_key().clone())
+            .with_send_asset(Asset::new_native())
+            .with_send_amount(Stroops::new(1000))
+            .with_destination(keypair1().public_key().clone())
+            .with_dest_asset(Asset::new_native())
+            .with_dest_min(Stroops::new(100))
+            .with_path(Vec::new())
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAA0AAAAAAAAAAAAAA+gAAAAAJcrx2g/Hbs/ohF5CVFG7B5JJSJR+OqDKzDGK7dKHZH4AAAAAAAAAAAAAAGQAAAAA",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
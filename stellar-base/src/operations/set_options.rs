@@ -0,0 +1,346 @@
+use crate::crypto::{MuxedAccount, PublicKey};
+use crate::error::{Error, Result};
+use crate::operations::Operation;
+use crate::xdr;
+
+/// The weight and key of a signer to add, update, or remove from an
+/// account.
+///
+/// Setting `weight` to `0` removes the signer. Any other value adds the
+/// signer or updates its existing weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signer {
+    key: SignerKey,
+    weight: u32,
+}
+
+/// The key identifying a [`Signer`], which can be an additional ed25519
+/// public key, the hash of a pre-authorized transaction, or the hash of a
+/// hashx signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerKey {
+    Ed25519(PublicKey),
+    PreAuthTx([u8; 32]),
+    HashX([u8; 32]),
+}
+
+impl Signer {
+    /// Creates a new signer with the given key and weight.
+    pub fn new(key: SignerKey, weight: u32) -> Signer {
+        Signer { key, weight }
+    }
+
+    /// Retrieves the signer's key.
+    pub fn key(&self) -> &SignerKey {
+        &self.key
+    }
+
+    /// Retrieves the signer's weight.
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    fn to_xdr(&self) -> Result<xdr::Signer> {
+        let key = match &self.key {
+            SignerKey::Ed25519(public_key) => {
+                let xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(raw)) =
+                    public_key.to_xdr()?;
+                xdr::SignerKey::SignerKeyTypeEd25519(raw)
+            }
+            SignerKey::PreAuthTx(hash) => xdr::SignerKey::SignerKeyTypePreAuthTx(*hash),
+            SignerKey::HashX(hash) => xdr::SignerKey::SignerKeyTypeHashX(*hash),
+        };
+        Ok(xdr::Signer {
+            key,
+            weight: self.weight,
+        })
+    }
+
+    fn from_xdr(x: &xdr::Signer) -> Result<Signer> {
+        let key = match &x.key {
+            xdr::SignerKey::SignerKeyTypeEd25519(raw) => {
+                let account_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(*raw));
+                SignerKey::Ed25519(PublicKey::from_xdr(&account_id)?)
+            }
+            xdr::SignerKey::SignerKeyTypePreAuthTx(hash) => SignerKey::PreAuthTx(*hash),
+            xdr::SignerKey::SignerKeyTypeHashX(hash) => SignerKey::HashX(*hash),
+        };
+        Ok(Signer {
+            key,
+            weight: x.weight,
+        })
+    }
+}
+
+/// Configures an account: its inflation destination, flags, thresholds,
+/// master weight, home domain, and signers.
+///
+/// This is the operation used to set up multisignature accounts: adding an
+/// extra [`Signer`] and raising `med_threshold`/`high_threshold` above
+/// `master_weight` means transactions need signatures from more than one
+/// key to meet the relevant threshold.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetOptionsOperation {
+    source_account: Option<MuxedAccount>,
+    inflation_dest: Option<PublicKey>,
+    clear_flags: Option<u32>,
+    set_flags: Option<u32>,
+    master_weight: Option<u8>,
+    low_threshold: Option<u8>,
+    med_threshold: Option<u8>,
+    high_threshold: Option<u8>,
+    home_domain: Option<String>,
+    signer: Option<Signer>,
+}
+
+#[derive(Debug, Default)]
+pub struct SetOptionsOperationBuilder {
+    source_account: Option<MuxedAccount>,
+    inflation_dest: Option<PublicKey>,
+    clear_flags: Option<u32>,
+    set_flags: Option<u32>,
+    master_weight: Option<u8>,
+    low_threshold: Option<u8>,
+    med_threshold: Option<u8>,
+    high_threshold: Option<u8>,
+    home_domain: Option<String>,
+    signer: Option<Signer>,
+}
+
+impl SetOptionsOperation {
+    /// Retrieves the operation source account.
+    pub fn source_account(&self) -> &Option<MuxedAccount> {
+        &self.source_account
+    }
+
+    /// Retrieves the inflation destination, if it is being changed.
+    pub fn inflation_dest(&self) -> &Option<PublicKey> {
+        &self.inflation_dest
+    }
+
+    /// Retrieves the account flags being cleared.
+    pub fn clear_flags(&self) -> &Option<u32> {
+        &self.clear_flags
+    }
+
+    /// Retrieves the account flags being set.
+    pub fn set_flags(&self) -> &Option<u32> {
+        &self.set_flags
+    }
+
+    /// Retrieves the new master key weight.
+    pub fn master_weight(&self) -> &Option<u8> {
+        &self.master_weight
+    }
+
+    /// Retrieves the new low threshold.
+    pub fn low_threshold(&self) -> &Option<u8> {
+        &self.low_threshold
+    }
+
+    /// Retrieves the new medium threshold.
+    pub fn med_threshold(&self) -> &Option<u8> {
+        &self.med_threshold
+    }
+
+    /// Retrieves the new high threshold.
+    pub fn high_threshold(&self) -> &Option<u8> {
+        &self.high_threshold
+    }
+
+    /// Retrieves the new home domain.
+    pub fn home_domain(&self) -> &Option<String> {
+        &self.home_domain
+    }
+
+    /// Retrieves the signer being added, updated, or removed.
+    pub fn signer(&self) -> &Option<Signer> {
+        &self.signer
+    }
+
+    pub fn to_xdr_operation_body(&self) -> Result<xdr::OperationBody> {
+        let inflation_dest = match &self.inflation_dest {
+            None => None,
+            Some(key) => Some(key.to_xdr()?),
+        };
+        let home_domain = match &self.home_domain {
+            None => None,
+            Some(domain) => Some(domain.clone().into_bytes()),
+        };
+        let signer = match &self.signer {
+            None => None,
+            Some(signer) => Some(signer.to_xdr()?),
+        };
+        let inner = xdr::SetOptionsOp {
+            inflation_dest,
+            clear_flags: self.clear_flags,
+            set_flags: self.set_flags,
+            master_weight: self.master_weight.map(|w| w as u32),
+            low_threshold: self.low_threshold.map(|w| w as u32),
+            med_threshold: self.med_threshold.map(|w| w as u32),
+            high_threshold: self.high_threshold.map(|w| w as u32),
+            home_domain,
+            signer,
+        };
+        Ok(xdr::OperationBody::SetOptions(inner))
+    }
+
+    pub fn from_xdr_operation_body(
+        source_account: Option<MuxedAccount>,
+        x: &xdr::SetOptionsOp,
+    ) -> Result<SetOptionsOperation> {
+        let inflation_dest = match &x.inflation_dest {
+            None => None,
+            Some(account_id) => Some(PublicKey::from_xdr(account_id)?),
+        };
+        let home_domain = match &x.home_domain {
+            None => None,
+            Some(bytes) => Some(
+                String::from_utf8(bytes.clone())
+                    .map_err(|_| Error::InvalidOperation("invalid home domain".to_string()))?,
+            ),
+        };
+        let signer = match &x.signer {
+            None => None,
+            Some(signer) => Some(Signer::from_xdr(signer)?),
+        };
+        Ok(SetOptionsOperation {
+            source_account,
+            inflation_dest,
+            clear_flags: x.clear_flags,
+            set_flags: x.set_flags,
+            master_weight: x.master_weight.map(|w| w as u8),
+            low_threshold: x.low_threshold.map(|w| w as u8),
+            med_threshold: x.med_threshold.map(|w| w as u8),
+            high_threshold: x.high_threshold.map(|w| w as u8),
+            home_domain,
+            signer,
+        })
+    }
+}
+
+impl SetOptionsOperationBuilder {
+    pub fn new() -> SetOptionsOperationBuilder {
+        Default::default()
+    }
+
+    /// Sets the operation source account.
+    pub fn with_source_account<S>(mut self, source: S) -> SetOptionsOperationBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source.into());
+        self
+    }
+
+    /// Sets the inflation destination.
+    pub fn with_inflation_dest(mut self, inflation_dest: PublicKey) -> SetOptionsOperationBuilder {
+        self.inflation_dest = Some(inflation_dest);
+        self
+    }
+
+    /// Sets the account flags to clear.
+    pub fn with_clear_flags(mut self, clear_flags: u32) -> SetOptionsOperationBuilder {
+        self.clear_flags = Some(clear_flags);
+        self
+    }
+
+    /// Sets the account flags to set.
+    pub fn with_set_flags(mut self, set_flags: u32) -> SetOptionsOperationBuilder {
+        self.set_flags = Some(set_flags);
+        self
+    }
+
+    /// Sets the new master key weight.
+    pub fn with_master_weight(mut self, master_weight: u8) -> SetOptionsOperationBuilder {
+        self.master_weight = Some(master_weight);
+        self
+    }
+
+    /// Sets the new low threshold.
+    pub fn with_low_threshold(mut self, low_threshold: u8) -> SetOptionsOperationBuilder {
+        self.low_threshold = Some(low_threshold);
+        self
+    }
+
+    /// Sets the new medium threshold.
+    pub fn with_med_threshold(mut self, med_threshold: u8) -> SetOptionsOperationBuilder {
+        self.med_threshold = Some(med_threshold);
+        self
+    }
+
+    /// Sets the new high threshold.
+    pub fn with_high_threshold(mut self, high_threshold: u8) -> SetOptionsOperationBuilder {
+        self.high_threshold = Some(high_threshold);
+        self
+    }
+
+    /// Sets the new home domain.
+    pub fn with_home_domain(mut self, home_domain: String) -> SetOptionsOperationBuilder {
+        self.home_domain = Some(home_domain);
+        self
+    }
+
+    /// Sets the signer to add, update, or remove.
+    pub fn with_signer(mut self, signer: Signer) -> SetOptionsOperationBuilder {
+        self.signer = Some(signer);
+        self
+    }
+
+    pub fn build(self) -> Result<Operation> {
+        Ok(Operation::SetOptions(SetOptionsOperation {
+            source_account: self.source_account,
+            inflation_dest: self.inflation_dest,
+            clear_flags: self.clear_flags,
+            set_flags: self.set_flags,
+            master_weight: self.master_weight,
+            low_threshold: self.low_threshold,
+            med_threshold: self.med_threshold,
+            high_threshold: self.high_threshold,
+            home_domain: self.home_domain,
+            signer: self.signer,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::xdr::{XDRDeserialize, XDRSerialize};
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_set_options_add_signer_round_trip() {
+        let op = crate::operations::set_options()
+            .with_source_account(keypair0().public_key().clone())
+            .with_master_weight(1)
+            .with_med_threshold(2)
+            .with_high_threshold(2)
+            .with_signer(Signer::new(
+                SignerKey::Ed25519(keypair1().public_key().clone()),
+                1,
+            ))
+            .with_home_domain("stellar.org".to_string())
+            .build()
+            .unwrap();
+        let encoded = op.xdr_base64().unwrap();
+        assert_eq!(
+            "AAAAAQAAAADg3G3hclysZlFitS+s5zWyiiJD5B0STWy5LXCj6i5yxQAAAAUAAAAAAAAAAAAAAAAAAAABAAAAAQAAAAAAAAABAAAAAgAAAAEAAAACAAAAAQAAAAtzdGVsbGFyLm9yZwAAAAABAAAAACXK8doPx27P6IReQlRRuweSSUiUfjqgyswxiu3Sh2R+AAAAAQ==",
+            encoded
+        );
+        let decoded = Operation::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(op, decoded);
+    }
+}
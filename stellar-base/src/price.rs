@@ -0,0 +1,126 @@
+use crate::error::{Error, Result};
+use crate::xdr;
+use std::str::FromStr;
+
+/// The price of an asset, expressed as the ratio of two 32-bit integers.
+///
+/// The ledger represents prices as a numerator/denominator pair rather than
+/// a floating point number, so that offers never depend on the rounding
+/// behaviour of a particular floating point implementation. `Price` converts
+/// a human readable price (e.g. `"3.75"` or `3.75_f64`) into the best
+/// rational approximation that fits the XDR representation, using the
+/// continued fraction expansion of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Price {
+    n: i32,
+    d: i32,
+}
+
+impl Price {
+    /// Creates a new `Price` from a numerator and a denominator.
+    pub fn new(n: i32, d: i32) -> Price {
+        Price { n, d }
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(&self) -> i32 {
+        self.n
+    }
+
+    /// Returns the denominator.
+    pub fn denominator(&self) -> i32 {
+        self.d
+    }
+
+    /// Returns the best rational approximation of `value` whose numerator
+    /// and denominator both fit in an `i32`, computed via the continued
+    /// fraction expansion of `value`.
+    pub fn from_f64(value: f64) -> Result<Price> {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(Error::InvalidPrice);
+        }
+
+        // Convergents of the continued fraction expansion of `value`, seeded
+        // with (h_{-1}, k_{-1}) = (0, 1) and (h_0, k_0) = (1, 0).
+        let (mut h_prev, mut k_prev): (i64, i64) = (0, 1);
+        let (mut h, mut k): (i64, i64) = (1, 0);
+        let mut f = value;
+
+        loop {
+            let a = f.floor();
+            let h_next = a as i64 * h + h_prev;
+            let k_next = a as i64 * k + k_prev;
+            if h_next > i32::MAX as i64 || k_next > i32::MAX as i64 {
+                break;
+            }
+            h_prev = h;
+            k_prev = k;
+            h = h_next;
+            k = k_next;
+
+            let fract = f - a;
+            if fract == 0.0 {
+                break;
+            }
+            f = 1.0 / fract;
+        }
+
+        if h == 0 || k == 0 {
+            return Err(Error::InvalidPrice);
+        }
+
+        Ok(Price {
+            n: h as i32,
+            d: k as i32,
+        })
+    }
+
+    /// Converts this `Price` to its XDR representation.
+    pub fn to_xdr(&self) -> Result<xdr::Price> {
+        Ok(xdr::Price {
+            n: self.n,
+            d: self.d,
+        })
+    }
+
+    /// Creates a new `Price` from its XDR representation.
+    pub fn from_xdr(x: &xdr::Price) -> Result<Price> {
+        Ok(Price { n: x.n, d: x.d })
+    }
+}
+
+impl FromStr for Price {
+    type Err = Error;
+
+    /// Parses a decimal string (e.g. `"3.75"`) into its best rational
+    /// approximation.
+    fn from_str(s: &str) -> Result<Price> {
+        let value: f64 = s.parse().map_err(|_| Error::InvalidPrice)?;
+        Price::from_f64(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_f64_exact() {
+        let price = Price::from_f64(0.5).unwrap();
+        assert_eq!(1, price.numerator());
+        assert_eq!(2, price.denominator());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let price: Price = "3.75".parse().unwrap();
+        assert_eq!(15, price.numerator());
+        assert_eq!(4, price.denominator());
+    }
+
+    #[test]
+    fn test_from_f64_rejects_non_positive() {
+        assert!(Price::from_f64(0.0).is_err());
+        assert!(Price::from_f64(-1.0).is_err());
+    }
+}
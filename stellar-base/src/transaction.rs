@@ -0,0 +1,323 @@
+use crate::crypto::{KeyPair, MuxedAccount};
+use crate::error::{Error, Result};
+use crate::memo::Memo;
+use crate::network::Network;
+use crate::operations::Operation;
+use crate::xdr;
+use crate::xdr::{XDRDeserialize, XDRSerialize};
+use sha2::{Digest, Sha256};
+use xdr_rs_serialize::de::XDRIn;
+use xdr_rs_serialize::ser::XDROut;
+
+/// The base fee, in stroops, charged per operation when a transaction does
+/// not set an explicit fee.
+const BASE_FEE: u32 = 100;
+
+/// The maximum number of operations a single transaction may contain.
+const MAX_OPERATIONS: usize = 100;
+
+/// A Stellar transaction: an ordered list of operations submitted together
+/// under a single source account and sequence number, along with whatever
+/// signatures have been collected for it so far.
+///
+/// A `Transaction` can be signed by more than one party before being
+/// submitted. One signer builds the transaction, signs it, and serializes
+/// it to base64 XDR; a second party deserializes it with
+/// [`Transaction::from_xdr_base64`], appends their own signature with
+/// [`Transaction::sign`] or [`Transaction::append_signature`], and the
+/// process repeats until enough signatures are collected to meet the
+/// source account's thresholds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    source_account: MuxedAccount,
+    fee: u32,
+    seq_num: i64,
+    memo: Memo,
+    operations: Vec<Operation>,
+    signatures: Vec<xdr::DecoratedSignature>,
+}
+
+#[derive(Debug)]
+pub struct TransactionBuilder {
+    source_account: Option<MuxedAccount>,
+    seq_num: Option<i64>,
+    fee: Option<u32>,
+    memo: Memo,
+    operations: Vec<Operation>,
+}
+
+impl Transaction {
+    /// Retrieves the transaction source account.
+    pub fn source_account(&self) -> &MuxedAccount {
+        &self.source_account
+    }
+
+    /// Retrieves the total fee, in stroops, the source account is charged.
+    pub fn fee(&self) -> u32 {
+        self.fee
+    }
+
+    /// Retrieves the sequence number consumed by this transaction.
+    pub fn seq_num(&self) -> i64 {
+        self.seq_num
+    }
+
+    /// Retrieves the transaction memo.
+    pub fn memo(&self) -> &Memo {
+        &self.memo
+    }
+
+    /// Retrieves the transaction's operations.
+    pub fn operations(&self) -> &Vec<Operation> {
+        &self.operations
+    }
+
+    /// Retrieves the signatures collected for this transaction so far.
+    pub fn signatures(&self) -> &Vec<xdr::DecoratedSignature> {
+        &self.signatures
+    }
+
+    /// Appends a signature collected out of band, e.g. from a hardware or
+    /// remote signer, without discarding any signature already present.
+    pub fn append_signature(&mut self, signature: xdr::DecoratedSignature) {
+        self.signatures.push(signature);
+    }
+
+    /// Computes the network-scoped hash of this transaction and appends a
+    /// new signature from `key`, without discarding any signature a
+    /// different party may have already added.
+    pub fn sign(&mut self, key: &KeyPair, network: &Network) -> Result<()> {
+        let hash = self.hash(network)?;
+        let signature = key.sign(&hash)?;
+        let hint = key.public_key().signature_hint()?;
+        self.signatures
+            .push(xdr::DecoratedSignature { hint, signature });
+        Ok(())
+    }
+
+    /// Computes the network-scoped transaction hash that signatures are
+    /// made over: the SHA-256 digest of the network id, the `ENVELOPE_TYPE_TX`
+    /// tag, and the XDR-encoded transaction.
+    pub fn hash(&self, network: &Network) -> Result<[u8; 32]> {
+        let mut out = network.network_id().to_vec();
+        xdr::EnvelopeType::EnvelopeTypeTx
+            .write_xdr(&mut out)
+            .map_err(Error::XdrError)?;
+        self.to_xdr_transaction()?
+            .write_xdr(&mut out)
+            .map_err(Error::XdrError)?;
+        let digest = Sha256::digest(&out);
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&digest);
+        Ok(hash)
+    }
+
+    fn to_xdr_transaction(&self) -> Result<xdr::Transaction> {
+        let source_account = self.source_account.to_xdr()?;
+        let memo = self.memo.to_xdr()?;
+        let operations = self
+            .operations
+            .iter()
+            .map(Operation::to_xdr)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(xdr::Transaction {
+            source_account,
+            fee: self.fee,
+            seq_num: self.seq_num,
+            time_bounds: None,
+            memo,
+            operations,
+            ext: xdr::TransactionExt::V0,
+        })
+    }
+
+    /// Converts this transaction, together with its signatures, to a
+    /// signable XDR transaction envelope.
+    pub fn to_xdr(&self) -> Result<xdr::TransactionEnvelope> {
+        let tx = self.to_xdr_transaction()?;
+        let envelope = xdr::TransactionV1Envelope {
+            tx,
+            signatures: self.signatures.clone(),
+        };
+        Ok(xdr::TransactionEnvelope::EnvelopeTypeTx(envelope))
+    }
+
+    /// Creates a `Transaction` from its XDR transaction envelope, keeping
+    /// whatever signatures the envelope already carries.
+    pub fn from_xdr(x: &xdr::TransactionEnvelope) -> Result<Transaction> {
+        match x {
+            xdr::TransactionEnvelope::EnvelopeTypeTx(envelope) => {
+                let source_account = MuxedAccount::from_xdr(&envelope.tx.source_account)?;
+                let memo = Memo::from_xdr(&envelope.tx.memo)?;
+                let operations = envelope
+                    .tx
+                    .operations
+                    .iter()
+                    .map(Operation::from_xdr)
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Transaction {
+                    source_account,
+                    fee: envelope.tx.fee,
+                    seq_num: envelope.tx.seq_num,
+                    memo,
+                    operations,
+                    signatures: envelope.signatures.clone(),
+                })
+            }
+        }
+    }
+}
+
+impl XDRSerialize for Transaction {
+    fn write_xdr(&self, mut out: &mut Vec<u8>) -> Result<u64> {
+        let envelope = self.to_xdr()?;
+        envelope.write_xdr(&mut out).map_err(Error::XdrError)
+    }
+}
+
+impl XDRDeserialize for Transaction {
+    fn from_xdr_bytes(buffer: &[u8]) -> Result<(Self, u64)> {
+        let (envelope, bytes_read) =
+            xdr::TransactionEnvelope::read_xdr(&buffer).map_err(Error::XdrError)?;
+        let res = Transaction::from_xdr(&envelope)?;
+        Ok((res, bytes_read))
+    }
+}
+
+impl TransactionBuilder {
+    pub fn new() -> TransactionBuilder {
+        TransactionBuilder {
+            source_account: None,
+            seq_num: None,
+            fee: None,
+            memo: Memo::none(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Sets the transaction source account.
+    pub fn with_source_account<S>(mut self, source_account: S) -> TransactionBuilder
+    where
+        S: Into<MuxedAccount>,
+    {
+        self.source_account = Some(source_account.into());
+        self
+    }
+
+    /// Sets the sequence number this transaction consumes. This must be one
+    /// greater than the source account's current sequence number.
+    pub fn with_sequence_number(mut self, seq_num: i64) -> TransactionBuilder {
+        self.seq_num = Some(seq_num);
+        self
+    }
+
+    /// Sets the total fee, in stroops, the source account is charged. If
+    /// left unset, the fee defaults to the base fee times the number of
+    /// operations.
+    pub fn with_fee(mut self, fee: u32) -> TransactionBuilder {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Sets the transaction memo. Defaults to [`Memo::none()`].
+    pub fn with_memo(mut self, memo: Memo) -> TransactionBuilder {
+        self.memo = memo;
+        self
+    }
+
+    /// Appends an operation to the transaction.
+    pub fn add_operation(mut self, operation: Operation) -> TransactionBuilder {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn build(self) -> Result<Transaction> {
+        let source_account = self
+            .source_account
+            .ok_or_else(|| Error::InvalidOperation("missing source account".to_string()))?;
+        let seq_num = self
+            .seq_num
+            .ok_or_else(|| Error::InvalidOperation("missing sequence number".to_string()))?;
+        if self.operations.is_empty() {
+            return Err(Error::InvalidOperation(
+                "transaction must contain at least one operation".to_string(),
+            ));
+        }
+        if self.operations.len() > MAX_OPERATIONS {
+            return Err(Error::InvalidOperation(
+                "transaction contains too many operations".to_string(),
+            ));
+        }
+        let fee = self
+            .fee
+            .unwrap_or_else(|| BASE_FEE * self.operations.len() as u32);
+        Ok(Transaction {
+            source_account,
+            fee,
+            seq_num,
+            memo: self.memo,
+            operations: self.operations,
+            signatures: Vec::new(),
+        })
+    }
+}
+
+impl Default for TransactionBuilder {
+    fn default() -> TransactionBuilder {
+        TransactionBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyPair;
+    use crate::operations::inflation;
+
+    fn keypair0() -> KeyPair {
+        // GDQNY3PBOJOKYZSRMK2S7LHHGWZIUISD4QORETLMXEWXBI7KFZZMKTL3
+        KeyPair::from_secret_seed("SBPQUZ6G4FZNWFHKUWC5BEYWF6R52E3SEP7R3GWYSM2XTKGF5LNTWW4R")
+            .unwrap()
+    }
+
+    fn keypair1() -> KeyPair {
+        // GAS4V4O2B7DW5T7IQRPEEVCRXMDZESKISR7DVIGKZQYYV3OSQ5SH5LVP
+        KeyPair::from_secret_seed("SBMSVD4KKELKGZXHBUQTIROWUAPQASDX7KEJITARP4VMZ6KLUHOGPTYW")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_with_single_signature() {
+        let mut tx = TransactionBuilder::new()
+            .with_source_account(keypair0().public_key().clone())
+            .with_sequence_number(1)
+            .add_operation(inflation().build())
+            .build()
+            .unwrap();
+        tx.sign(&keypair0(), &Network::test()).unwrap();
+        let encoded = tx.xdr_base64().unwrap();
+        let decoded = Transaction::from_xdr_base64(&encoded).unwrap();
+        assert_eq!(tx, decoded);
+        assert_eq!(1, decoded.signatures().len());
+    }
+
+    #[test]
+    fn test_multi_signer_append_without_discarding() {
+        let mut tx = TransactionBuilder::new()
+            .with_source_account(keypair0().public_key().clone())
+            .with_sequence_number(1)
+            .add_operation(inflation().build())
+            .build()
+            .unwrap();
+        tx.sign(&keypair0(), &Network::test()).unwrap();
+        let encoded = tx.xdr_base64().unwrap();
+
+        // A second party receives the partially-signed envelope and adds
+        // their own signature without discarding the first one.
+        let mut received = Transaction::from_xdr_base64(&encoded).unwrap();
+        received.sign(&keypair1(), &Network::test()).unwrap();
+
+        assert_eq!(2, received.signatures().len());
+        assert_eq!(&tx.signatures()[0], &received.signatures()[0]);
+    }
+}